@@ -18,9 +18,13 @@
 //! The logging macros support the same string formatting as `solana_program::msg`.
 
 /// Represents a logging level. Levels are ordered from least to most important
-/// as `Debug`, `Info`, `Warn`, and `Error`.
+/// as `Trace`, `Debug`, `Info`, `Warn`, and `Error`.
 #[derive(Ord, Eq, PartialOrd, PartialEq)]
 pub enum Level {
+    /// Very high-volume diagnostics, noisier than `Debug`. Kept separate so
+    /// `loglevel_debug` builds can stay quiet while still capturing the
+    /// noisiest traces on demand via `loglevel_trace`.
+    Trace,
     Debug,
     Info,
     Warn,
@@ -31,8 +35,53 @@ pub enum Level {
     Disabled,
 }
 
+/// Renders a byte slice as lowercase hex, one `{:02x}` per byte, without
+/// allocating. Build with `log_bytes!` rather than constructing directly.
+pub struct HexBytes<'a>(pub &'a [u8]);
+
+impl<'a> std::fmt::Display for HexBytes<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for byte in self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+/// Renders a `Pubkey` in its canonical form. Build with `log_pubkey!` rather
+/// than constructing directly.
+pub struct PubkeyDisplay<'a>(pub &'a solana_program::pubkey::Pubkey);
+
+impl<'a> std::fmt::Display for PubkeyDisplay<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.0, f)
+    }
+}
+
+/// Wraps a `&[u8]` so it renders as lowercase hex inside a logging macro,
+/// e.g. `info!("key {}", log_bytes!(bytes))`.
+#[macro_export]
+macro_rules! log_bytes {
+    ($bytes:expr) => {
+        $crate::HexBytes($bytes)
+    };
+}
+
+/// Wraps a `&Pubkey` so it renders in its canonical form inside a logging
+/// macro, e.g. `info!("key {}", log_pubkey!(account_info.key))`.
+#[macro_export]
+macro_rules! log_pubkey {
+    ($pubkey:expr) => {
+        $crate::PubkeyDisplay($pubkey)
+    };
+}
+
 /// Returns the configured log level.
 pub fn level() -> Level {
+    if cfg!(feature = "loglevel_trace") {
+        return Level::Trace;
+    }
+
     if cfg!(feature = "loglevel_debug") {
         return Level::Debug;
     }
@@ -54,11 +103,25 @@ pub fn level() -> Level {
 
 /// Conditionally logs a message. Users should prefer one of the predefined
 /// message macros `debug`, `info`, `warn`, or `error`.
+///
+/// Accepts an optional `target: "..."` argument right after the label, like
+/// `log!(Level::Info, "INFO", target: "swap", "...")`, to tag the message with
+/// its logical subsystem. The level macros take it as a leading prefix
+/// instead, like `info!(target: "swap", "...")`.
 #[macro_export]
 macro_rules! log {
     (prefix $label:expr, $fmt:expr) => {
         concat!("[", file!(), ":", line!(), " ", $label, "] ", $fmt)
     };
+    (prefix $label:expr, target: $target:expr, $fmt:expr) => {
+        concat!("[", file!(), ":", line!(), " ", $target, " ", $label, "] ", $fmt)
+    };
+    ($level: expr, $label: expr, target: $target:expr, $fmt:expr, $($opt:expr),*) => {
+		solana_program::msg!($crate::log!(prefix $label, target: $target, $fmt), $($opt),*);
+    };
+    ($level: expr, $label: expr, target: $target:expr, $opt:expr) => {
+		solana_program::msg!($crate::log!(prefix $label, target: $target, "{}"), $opt);
+    };
     ($level: expr, $label: expr, $fmt:expr, $($opt:expr),*) => {
 		solana_program::msg!($crate::log!(prefix $label, $fmt), $($opt),*);
     };
@@ -67,44 +130,147 @@ macro_rules! log {
     };
 }
 
-/// Emits a message if the logging level is set to `Debug` or below.
+/// Expands to a `true`/`false` value indicating whether the given level is
+/// enabled by the configured `loglevel_*` feature. Unlike the level macros,
+/// each arm's condition is evaluated with `cfg!` rather than `#[cfg]`, so any
+/// computation gated on the result is eliminated by the compiler when the
+/// level is disabled. Accepts any expression of type `Level`, not just a
+/// bare `Level::X` path, so it works with a qualified path or an alias.
+///
+/// ```rust
+/// use solana_logger::{log_enabled, Level};
+///
+/// if log_enabled!(Level::Debug) {
+///     // Build an expensive debug string here.
+/// }
+/// ```
+#[macro_export]
+macro_rules! log_enabled {
+    ($lvl:expr) => {
+        match $lvl {
+            $crate::Level::Trace => cfg!(feature = "loglevel_trace"),
+            $crate::Level::Debug => cfg!(feature = "loglevel_debug"),
+            $crate::Level::Info => cfg!(feature = "loglevel_info"),
+            $crate::Level::Warn => cfg!(feature = "loglevel_warn"),
+            $crate::Level::Error => cfg!(feature = "loglevel_error"),
+            $crate::Level::Disabled => false,
+        }
+    };
+}
+
+/// Emits a message if the logging level is set to `Trace` or below. Accepts
+/// an optional `target: "..."` prefix, as in `log!`.
+#[macro_export]
+macro_rules! trace {
+    (target: $target:expr, $($opt:expr),*) => {
+		#[cfg(feature = "loglevel_trace")]
+        $crate::log!($crate::Level::Trace, "TRACE", target: $target, $($opt),*);
+    };
+    ($($opt:expr),*) => {
+		#[cfg(feature = "loglevel_trace")]
+        $crate::log!($crate::Level::Trace, "TRACE", $($opt),*);
+    };
+}
+
+/// Emits a message if the logging level is set to `Debug` or below. Accepts
+/// an optional `target: "..."` prefix, as in `log!`.
 #[macro_export]
 macro_rules! debug {
+    (target: $target:expr, $($opt:expr),*) => {
+		#[cfg(feature = "loglevel_debug")]
+        $crate::log!($crate::Level::Debug, "DEBUG", target: $target, $($opt),*);
+    };
     ($($opt:expr),*) => {
 		#[cfg(feature = "loglevel_debug")]
         $crate::log!($crate::Level::Debug, "DEBUG", $($opt),*);
     };
 }
 
-/// Emits a message if the logging level is set to `Info` or below.
+/// Emits a message if the logging level is set to `Info` or below. Accepts
+/// an optional `target: "..."` prefix, as in `log!`.
 #[macro_export]
 macro_rules! info {
+    (target: $target:expr, $($opt:expr),*) => {
+		#[cfg(feature = "loglevel_info")]
+        $crate::log!($crate::Level::Info, "INFO", target: $target, $($opt),*);
+    };
     ($($opt:expr),*) => {
 		#[cfg(feature = "loglevel_info")]
         $crate::log!($crate::Level::Info, "INFO", $($opt),*);
     };
 }
 
-/// Emits a message if the logging level is set to `Warn` or below.
+/// Emits a message if the logging level is set to `Warn` or below. Accepts
+/// an optional `target: "..."` prefix, as in `log!`.
 #[macro_export]
 macro_rules! warn {
+    (target: $target:expr, $($opt:expr),*) => {
+		#[cfg(feature = "loglevel_warn")]
+        $crate::log!($crate::Level::Warn, "WARN", target: $target, $($opt),*);
+    };
     ($($opt:expr),*) => {
 		#[cfg(feature = "loglevel_warn")]
         $crate::log!($crate::Level::Warn, "WARN", $($opt),*);
     };
 }
 
-/// Emits a message if the logging level is set to `Error` or below.
+/// Emits a message if the logging level is set to `Error` or below. Accepts
+/// an optional `target: "..."` prefix, as in `log!`.
 #[macro_export]
 macro_rules! error {
+    (target: $target:expr, $($opt:expr),*) => {
+		#[cfg(feature = "loglevel_error")]
+        $crate::log!($crate::Level::Error, "ERROR", target: $target, $($opt),*);
+    };
     ($($opt:expr),*) => {
 		#[cfg(feature = "loglevel_error")]
         $crate::log!($crate::Level::Error, "ERROR", $($opt),*);
     };
 }
 
+/// Emits a message at a level computed at runtime. Each possible level is
+/// matched against its own `#[cfg(feature = "...")]` gate, so call sites that
+/// don't know their severity until runtime (for example, logging an error or
+/// a warning depending on a result code) still get the disabled arms
+/// stripped out at compile time.
+#[macro_export]
+macro_rules! log_given_level {
+    ($level:expr, target: $target:expr, $($opt:expr),*) => {
+        match $level {
+            #[cfg(feature = "loglevel_trace")]
+            $crate::Level::Trace => { $crate::log!($crate::Level::Trace, "TRACE", target: $target, $($opt),*); },
+            #[cfg(feature = "loglevel_debug")]
+            $crate::Level::Debug => { $crate::log!($crate::Level::Debug, "DEBUG", target: $target, $($opt),*); },
+            #[cfg(feature = "loglevel_info")]
+            $crate::Level::Info => { $crate::log!($crate::Level::Info, "INFO", target: $target, $($opt),*); },
+            #[cfg(feature = "loglevel_warn")]
+            $crate::Level::Warn => { $crate::log!($crate::Level::Warn, "WARN", target: $target, $($opt),*); },
+            #[cfg(feature = "loglevel_error")]
+            $crate::Level::Error => { $crate::log!($crate::Level::Error, "ERROR", target: $target, $($opt),*); },
+            _ => {}
+        }
+    };
+    ($level:expr, $($opt:expr),*) => {
+        match $level {
+            #[cfg(feature = "loglevel_trace")]
+            $crate::Level::Trace => { $crate::log!($crate::Level::Trace, "TRACE", $($opt),*); },
+            #[cfg(feature = "loglevel_debug")]
+            $crate::Level::Debug => { $crate::log!($crate::Level::Debug, "DEBUG", $($opt),*); },
+            #[cfg(feature = "loglevel_info")]
+            $crate::Level::Info => { $crate::log!($crate::Level::Info, "INFO", $($opt),*); },
+            #[cfg(feature = "loglevel_warn")]
+            $crate::Level::Warn => { $crate::log!($crate::Level::Warn, "WARN", $($opt),*); },
+            #[cfg(feature = "loglevel_error")]
+            $crate::Level::Error => { $crate::log!($crate::Level::Error, "ERROR", $($opt),*); },
+            _ => {}
+        }
+    };
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
+
     #[test]
     fn ignore_info() {
         info!("should not display");
@@ -124,4 +290,56 @@ mod tests {
     fn apply_formatting() {
         error!("hello {}", "world");
     }
+
+    #[test]
+    fn log_enabled_reflects_features() {
+        let (trace, debug, info, warn, error) = (
+            log_enabled!(Level::Trace),
+            log_enabled!(Level::Debug),
+            log_enabled!(Level::Info),
+            log_enabled!(Level::Warn),
+            log_enabled!(Level::Error),
+        );
+        assert!(!trace);
+        assert!(!debug);
+        assert!(!info);
+        assert!(warn);
+        assert!(error);
+    }
+
+    #[test]
+    fn ignore_trace() {
+        trace!("should not display");
+    }
+
+    #[test]
+    fn given_level_dispatches_at_runtime() {
+        log_given_level!(Level::Warn, "should display");
+        log_given_level!(Level::Error, "should display");
+        log_given_level!(Level::Info, "should not display");
+    }
+
+    #[test]
+    fn given_level_dispatches_at_runtime_with_target() {
+        log_given_level!(Level::Warn, target: "swap", "should display with target");
+        log_given_level!(Level::Error, target: "swap", "hello {}", "world");
+        log_given_level!(Level::Info, target: "swap", "should not display");
+    }
+
+    #[test]
+    fn tags_message_with_target() {
+        warn!(target: "swap", "should display with target");
+        error!(target: "swap", "hello {}", "world");
+    }
+
+    #[test]
+    fn formats_bytes_and_pubkey() {
+        assert_eq!(format!("{}", log_bytes!(&[0x12u8, 0x34, 0xab])), "1234ab");
+
+        let pubkey = solana_program::pubkey::Pubkey::new_from_array([0u8; 32]);
+        assert_eq!(
+            format!("{}", log_pubkey!(&pubkey)),
+            pubkey.to_string()
+        );
+    }
 }